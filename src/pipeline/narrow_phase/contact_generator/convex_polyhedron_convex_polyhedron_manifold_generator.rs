@@ -2,23 +2,248 @@ use na::{self, Point2, Real, Unit};
 
 #[cfg(feature = "dim3")]
 use alga::linear::FiniteDimInnerSpace;
-use math::{Isometry, Vector};
+use math::{Isometry, Point, Vector};
 use pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use query::algorithms::gjk::GJKResult;
 use query::algorithms::CSOPoint;
 use query::algorithms::VoronoiSimplex;
-#[cfg(feature = "dim3")]
 use query::closest_points_internal;
 use query::contacts_internal;
 #[cfg(feature = "dim3")]
 use query::ray_internal;
 use query::{Contact, ContactKinematic, ContactManifold, ContactPrediction};
 use shape::ConvexPolygonalFeature;
-use shape::{ConvexPolyhedron, FeatureId, Segment, SegmentPointLocation, Shape};
+use shape::{ConvexPolyhedron, Cuboid, FeatureId, Segment, SegmentPointLocation, Shape};
 #[cfg(feature = "dim3")]
-use utils::{self, PolylinePointLocation};
+use shape::Triangle;
 use utils::{IdAllocator, IsometryOps};
 
+/// How an edge adjacent to the primary contact feature relates to it, around their shared
+/// edge direction.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum AdjacentEdgeClass {
+    /// The adjacent face folds away from the primary face: a real silhouette edge that can
+    /// clamp the contact normal.
+    Convex,
+    /// The adjacent face is coplanar with the primary one (e.g. the other half of a
+    /// triangulated quad): it never constrains the normal.
+    Flat,
+    /// The adjacent face folds back behind the primary one: it is never the right contact
+    /// here and generates its own manifold instead.
+    Concave,
+}
+
+/// A face neighboring the primary contact feature of a segment or triangle embedded in a
+/// chain (a polyline or a heightfield row/column), used to avoid catching on the internal
+/// ("ghost") vertex shared by the two faces.
+///
+/// `PolylineConvexPolyhedronManifoldGenerator` and `HeightFieldConvexPolyhedronManifoldGenerator`
+/// (which dispatch one instance of this generator per segment/triangle) are expected to call
+/// [`ConvexPolyhedronConvexPolyhedronManifoldGenerator::set_adjacent_edges1`] with the
+/// neighbors of the sub-shape's edges before each `update`, by convention always treating the
+/// chain element as shape 1.
+#[derive(Clone)]
+pub struct AdjacentEdge<N: Real> {
+    /// Outward normal of the face on the other side of the shared edge, in shape 1's local
+    /// space.
+    pub normal: Unit<Vector<N>>,
+    /// Direction of the shared edge itself, in shape 1's local space.
+    pub edge_dir: Unit<Vector<N>>,
+}
+
+impl<N: Real> AdjacentEdge<N> {
+    /// Creates a new adjacent-edge descriptor from the neighbor's outward normal and the
+    /// direction of the edge the two faces share.
+    pub fn new(normal: Unit<Vector<N>>, edge_dir: Unit<Vector<N>>) -> Self {
+        AdjacentEdge { normal, edge_dir }
+    }
+
+    fn classify(&self, primary_normal: &Unit<Vector<N>>) -> AdjacentEdgeClass {
+        let _1: N = na::one();
+        let dot = na::dot(primary_normal.as_ref(), self.normal.as_ref());
+
+        if dot >= _1 - N::default_epsilon() {
+            return AdjacentEdgeClass::Flat;
+        }
+
+        // The fold is convex if the adjacent normal rotates away from the primary face
+        // around the shared edge direction.
+        if self.fold_sign(primary_normal) >= na::zero() {
+            AdjacentEdgeClass::Convex
+        } else {
+            AdjacentEdgeClass::Concave
+        }
+    }
+
+    /// Right-hand-rule dihedral test between `primary_normal` and this edge's normal, positive
+    /// iff the fold is convex.
+    #[cfg(feature = "dim3")]
+    fn fold_sign(&self, primary_normal: &Unit<Vector<N>>) -> N {
+        let cross = primary_normal.cross(self.normal.as_ref());
+        na::dot(&cross, self.edge_dir.as_ref())
+    }
+
+    /// Same test specialized for 2D: the shared "edge" is a single vertex, so there is no
+    /// third axis to project a cross product onto. The perp-dot of the two normals (the scalar
+    /// equivalent of the 3D cross product's out-of-plane component) already fully determines
+    /// the fold, so `edge_dir` plays no role here.
+    #[cfg(feature = "dim2")]
+    fn fold_sign(&self, primary_normal: &Unit<Vector<N>>) -> N {
+        let n1 = primary_normal.as_ref();
+        let n2 = self.normal.as_ref();
+        n1.x * n2.y - n1.y * n2.x
+    }
+
+    /// Clamps `normal` back onto `primary_normal` if it has drifted past this edge into the
+    /// adjacent face's half-space.
+    fn clamp(&self, primary_normal: &Unit<Vector<N>>, normal: Unit<Vector<N>>) -> Unit<Vector<N>> {
+        if na::dot(normal.as_ref(), self.normal.as_ref())
+            > na::dot(primary_normal.as_ref(), self.normal.as_ref())
+        {
+            *primary_normal
+        } else {
+            normal
+        }
+    }
+}
+
+/// A shape whose boundary is a sharp convex polyhedron (its "core" hull, reported through the
+/// inherited `ConvexPolyhedron` methods) inflated by a constant border radius, e.g. a rounded
+/// box, a capsule, or a dilated convex hull.
+///
+/// Implementing this in addition to `ConvexPolyhedron` lets such shapes go through this module's
+/// GJK + clipping pipeline instead of falling back to a single-point detector: the core hull is
+/// clipped exactly as for a sharp polyhedron, and every resulting contact point is then pushed
+/// outward by `border_radius`.
+///
+/// CAUTION: no shape in this crate currently implements this with a nonzero radius (see the two
+/// impls below), so `margin` is always zero in practice and the inflation/margin-widening this
+/// module threads through `update`/`clip_polyfaces`/`inflate_contacts` has no observable effect
+/// yet. That plumbing is still correct and worth keeping, but a real rounded/capsule/dilated-hull
+/// shape type (and its own `PolygonalFeatureMap` impl, plus a case added to
+/// `polygonal_feature_map_border_radius` below) is needed before this request is actually
+/// delivered, not just scaffolded.
+pub trait PolygonalFeatureMap<N: Real>: ConvexPolyhedron<N> {
+    /// The radius by which the core polygonal hull is inflated to form this shape's actual
+    /// boundary. Zero for shapes that are already sharp polyhedra.
+    fn border_radius(&self) -> N;
+}
+
+impl<N: Real> PolygonalFeatureMap<N> for Cuboid<N> {
+    fn border_radius(&self) -> N {
+        N::zero()
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<N: Real> PolygonalFeatureMap<N> for Triangle<N> {
+    fn border_radius(&self) -> N {
+        N::zero()
+    }
+}
+
+/// Looks up `shape`'s `PolygonalFeatureMap` border radius, for any shape this module knows how
+/// to recognize. Mirrors `SatShape::from_shape`: there is no way to downcast to an arbitrary
+/// trait object without `Shape` itself exposing one, so new rounded/dilated shape types get a
+/// case added here (and a `PolygonalFeatureMap` impl next to their definition) once they exist
+/// in this crate.
+///
+/// Today this always returns zero: `Cuboid` and `Triangle` are sharp polyhedra by construction,
+/// and no rounded shape type exists in this crate yet to add a nonzero-returning case for.
+fn polygonal_feature_map_border_radius<N: Real>(shape: &Shape<N>) -> N {
+    if let Some(s) = shape.as_shape::<Cuboid<N>>() {
+        return PolygonalFeatureMap::border_radius(s);
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        if let Some(s) = shape.as_shape::<Triangle<N>>() {
+            return PolygonalFeatureMap::border_radius(s);
+        }
+    }
+
+    N::zero()
+}
+
+/// One of the shapes recognized by the SAT fast-path: axis-aligned boxes and triangles.
+///
+/// These are the only `ConvexPolyhedron` shapes whose faces are cheap to enumerate without
+/// walking a general half-edge structure, so `update` special-cases them instead of running
+/// full GJK + simplex iteration.
+enum SatShape<'a, N: Real> {
+    Cuboid(&'a Cuboid<N>),
+    #[cfg(feature = "dim3")]
+    Triangle(&'a Triangle<N>),
+}
+
+impl<'a, N: Real> SatShape<'a, N> {
+    fn from_shape(shape: &'a Shape<N>) -> Option<Self> {
+        if let Some(c) = shape.as_shape::<Cuboid<N>>() {
+            return Some(SatShape::Cuboid(c));
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            if let Some(t) = shape.as_shape::<Triangle<N>>() {
+                return Some(SatShape::Triangle(t));
+            }
+        }
+
+        None
+    }
+
+    /// The outward face normals of this shape, expressed in its own local space, paired with
+    /// the id of the face they come from.
+    fn local_face_axes(&self) -> Vec<(Unit<Vector<N>>, usize)> {
+        match *self {
+            SatShape::Cuboid(_) => {
+                let mut axes = Vec::with_capacity(Vector::<N>::dimension() * 2);
+                for i in 0..Vector::<N>::dimension() {
+                    let mut axis: Vector<N> = na::zero();
+                    axis[i] = na::one();
+                    axes.push((Unit::new_unchecked(axis), i * 2));
+                    axes.push((Unit::new_unchecked(-axis), i * 2 + 1));
+                }
+                axes
+            }
+            #[cfg(feature = "dim3")]
+            SatShape::Triangle(t) => {
+                if let Some(n) = t.normal() {
+                    vec![(n, 0), (Unit::new_unchecked(-*n), 1)]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    /// The direction of each edge of this shape, expressed in its own local space. Used to
+    /// build the cross-product axes of the 3D SAT.
+    #[cfg(feature = "dim3")]
+    fn local_edge_dirs(&self) -> Vec<Unit<Vector<N>>> {
+        match *self {
+            SatShape::Cuboid(_) => {
+                let mut dirs = Vec::with_capacity(3);
+                for i in 0..3 {
+                    let mut axis: Vector<N> = na::zero();
+                    axis[i] = na::one();
+                    dirs.push(Unit::new_unchecked(axis));
+                }
+                dirs
+            }
+            SatShape::Triangle(t) => {
+                let ab = *t.b() - *t.a();
+                let bc = *t.c() - *t.b();
+                let ca = *t.a() - *t.c();
+                [ab, bc, ca]
+                    .into_iter()
+                    .filter_map(|e| Unit::try_new(*e, N::default_epsilon()))
+                    .collect()
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 struct ClippingCache<N: Real> {
     poly1: Vec<Point2<N>>,
@@ -39,6 +264,182 @@ impl<N: Real> ClippingCache<N> {
     }
 }
 
+/// What an edge of the polygon being clipped (`poly1`, trimmed in place as we clip it against
+/// each edge of `poly2`) is a fragment of.
+#[derive(Copy, Clone)]
+enum ClipEdgeOwner {
+    /// Still part of the original `poly1` edge at this index.
+    Poly1(usize),
+    /// Runs along `poly2`'s edge at this index, having been introduced by an earlier clip.
+    Poly2(usize),
+}
+
+/// Where a vertex of the clipped polygon came from, used to recover the `FeatureId` pair of
+/// each final contact point.
+#[derive(Copy, Clone)]
+enum ClipVertexSource {
+    /// An original, unclipped vertex of `poly1`.
+    Poly1Vertex(usize),
+    /// Coincides with a vertex of `poly2` (found as the point where two successive clips
+    /// against `poly2`'s edges meet).
+    Poly2Vertex(usize),
+    /// A genuine crossing between `poly1`'s edge and `poly2`'s edge at these indices.
+    EdgeEdge(usize, usize),
+}
+
+/// Twice the signed area of `poly`, positive iff its vertices are wound counter-clockwise.
+fn polygon_signed_area2d<N: Real>(poly: &[Point2<N>]) -> N {
+    let mut area = N::zero();
+    let n = poly.len();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+        area += poly[i].x * poly[j].y - poly[j].x * poly[i].y;
+    }
+
+    area
+}
+
+/// Parameter `t` such that `p + (q - p) * t` lies on the (infinite) line through `a` and `b`,
+/// or `None` if segment `p`-`q` is parallel to it.
+fn segment_line_crossing<N: Real>(
+    p: &Point2<N>,
+    q: &Point2<N>,
+    a: &Point2<N>,
+    b: &Point2<N>,
+) -> Option<N> {
+    let d = *q - *p;
+    let e = *b - *a;
+    let denom = d.x * e.y - d.y * e.x;
+
+    if denom.abs() <= N::default_epsilon() {
+        return None;
+    }
+
+    let diff = *a - *p;
+    Some((diff.x * e.y - diff.y * e.x) / denom)
+}
+
+/// Clips the convex polygon `poly1` against the convex polygon `poly2`, both given in the same
+/// 2D basis, using the Sutherland-Hodgman algorithm specialized for convex inputs: since the
+/// intersection of a convex polygon with a half-plane is itself convex, each pass trims at most
+/// one contiguous run of vertices, with at most a single entry and a single exit crossing.
+///
+/// Returns the vertices of the intersection polygon (there may be none if the polygons don't
+/// overlap), each tagged with enough information to recover its `FeatureId` pair.
+fn clip_convex_polygons<N: Real>(
+    poly1: &[Point2<N>],
+    poly2: &[Point2<N>],
+) -> Vec<(Point2<N>, ClipVertexSource)> {
+    let n1 = poly1.len();
+
+    if n1 == 0 || poly2.len() < 3 {
+        return Vec::new();
+    }
+
+    let mut verts: Vec<(Point2<N>, ClipVertexSource)> = (0..n1)
+        .map(|i| (poly1[i], ClipVertexSource::Poly1Vertex(i)))
+        .collect();
+    let mut owners: Vec<ClipEdgeOwner> = (0..n1).map(ClipEdgeOwner::Poly1).collect();
+
+    // `poly1` and `poly2` are the support faces of two shapes whose normals point roughly
+    // opposite ways, so as seen in this shared 2D basis one of them is wound clockwise; fold
+    // that into the sign of the inside test instead of reversing either polygon (which would
+    // scramble the edge indices the feature ids below rely on).
+    let orient: N = if polygon_signed_area2d(poly2) >= na::zero() {
+        na::one()
+    } else {
+        -N::one()
+    };
+
+    let n2 = poly2.len();
+
+    for i2 in 0..n2 {
+        if verts.is_empty() {
+            break;
+        }
+
+        let a = poly2[i2];
+        let b = poly2[(i2 + 1) % n2];
+        let edge = b - a;
+        let inside = |p: &Point2<N>| -> bool {
+            let v = *p - a;
+            (edge.x * v.y - edge.y * v.x) * orient >= na::zero()
+        };
+
+        let n = verts.len();
+        let flags: Vec<bool> = verts.iter().map(|(p, _)| inside(p)).collect();
+
+        if flags.iter().all(|in_| *in_) {
+            continue;
+        }
+
+        if flags.iter().all(|in_| !*in_) {
+            verts.clear();
+            break;
+        }
+
+        let mut new_verts = Vec::with_capacity(n + 1);
+        let mut new_owners = Vec::with_capacity(n + 1);
+
+        for i in 0..n {
+            let j = (i + 1) % n;
+            let (cur, cur_src) = verts[i];
+            let cur_owner = owners[i];
+
+            if flags[i] {
+                new_verts.push((cur, cur_src));
+                new_owners.push(cur_owner);
+            }
+
+            if flags[i] != flags[j] {
+                let (next, _) = verts[j];
+
+                if let Some(t) = segment_line_crossing(&cur, &next, &a, &b) {
+                    let pt = Point2::new(cur.x + (next.x - cur.x) * t, cur.y + (next.y - cur.y) * t);
+
+                    let src = match cur_owner {
+                        ClipEdgeOwner::Poly1(k) => ClipVertexSource::EdgeEdge(k, i2),
+                        // The edge we just clipped is itself a fragment of a previous `poly2`
+                        // edge: this crossing is where that edge meets the current one, i.e.
+                        // the `poly2` vertex the two share.
+                        ClipEdgeOwner::Poly2(_) => ClipVertexSource::Poly2Vertex(i2),
+                    };
+
+                    new_verts.push((pt, src));
+                    new_owners.push(if flags[i] {
+                        // Leaving the half-plane: the new edge runs along `poly2`'s boundary
+                        // until the next entry point.
+                        ClipEdgeOwner::Poly2(i2)
+                    } else {
+                        cur_owner
+                    });
+                }
+            }
+        }
+
+        verts = new_verts;
+        owners = new_owners;
+    }
+
+    verts
+}
+
+/// The relative pose that produced the current `contact_manifold`, together with the
+/// invariant local anchors of each of its contacts, so `try_update_contacts` can re-project
+/// them onto a slightly different pose without rerunning GJK or the face clipper.
+#[derive(Clone)]
+struct WarmStartCache<N: Real> {
+    /// `ma.inverse() * mb` at the time of the last *full* SAT/GJK recompute. Left untouched by
+    /// warm-start reuses, so the drift thresholds in `try_update_contacts` bound cumulative
+    /// movement since that recompute rather than resetting every call.
+    mab: Isometry<N>,
+    /// Per contact: the anchor on shape 1 (in shape 1's local space, which never moves), the
+    /// anchor on shape 2 (in shape 2's *own* local space, which also never moves), the contact
+    /// normal (in shape 1's local space), and the two feature ids.
+    contacts: Vec<(Point<N>, Point<N>, Unit<Vector<N>>, FeatureId, FeatureId)>,
+}
+
 /// Persistent contact manifold computation between two shapes having a support mapping function.
 ///
 /// It is based on the GJK algorithm.  This detector generates only one contact point. For a full
@@ -53,6 +454,14 @@ pub struct ConvexPolyhedronConvexPolyhedronManifoldGenerator<N: Real> {
     manifold1: ConvexPolygonalFeature<N>,
     manifold2: ConvexPolygonalFeature<N>,
     sep_axis: Option<Unit<Vector<N>>>,
+    adjacent_edges1: Vec<AdjacentEdge<N>>,
+    warm_start: Option<WarmStartCache<N>>,
+    /// Maximum relative linear displacement, since the pose the cached manifold was built
+    /// from, under which `update` reuses its contacts instead of recomputing them. Set to
+    /// zero (the default is a small but non-zero value) to always recompute.
+    pub linear_warm_start_threshold: N,
+    /// Maximum relative rotation angle, in radians, under the same reuse rule.
+    pub angular_warm_start_threshold: N,
 }
 
 impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
@@ -67,9 +476,148 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
             manifold1: ConvexPolygonalFeature::new(),
             manifold2: ConvexPolygonalFeature::new(),
             sep_axis: None,
+            adjacent_edges1: Vec::new(),
+            warm_start: None,
+            linear_warm_start_threshold: na::convert(1.0e-3),
+            angular_warm_start_threshold: na::convert(1.0e-3),
+        }
+    }
+
+    /// Tries to reuse the contacts cached from the last full `update` instead of rerunning
+    /// GJK and the face clipper, when `mab` has barely moved since then.
+    ///
+    /// On success, `self.new_contacts` is filled with the re-projected contacts (with depth
+    /// recomputed along each contact's stored normal, and contacts that separated beyond
+    /// `prediction.linear + margin` dropped) and `true` is returned. Returns `false` when there
+    /// is no cache yet or the pose moved too much, leaving `self.new_contacts` untouched, or
+    /// when every cached contact was dropped, leaving `self.new_contacts` empty; either way the
+    /// caller falls back to a full recomputation.
+    fn try_update_contacts(
+        &mut self,
+        mab: &Isometry<N>,
+        prediction: &ContactPrediction<N>,
+        margin: N,
+    ) -> bool {
+        let warm_start = match self.warm_start {
+            Some(ref ws) => ws,
+            None => return false,
+        };
+
+        let dtransl = na::norm(&(mab.translation.vector - warm_start.mab.translation.vector));
+        if dtransl >= self.linear_warm_start_threshold {
+            return false;
+        }
+
+        let drot = warm_start.mab.rotation.inverse() * mab.rotation;
+        if drot.angle() >= self.angular_warm_start_threshold {
+            return false;
+        }
+
+        self.new_contacts.clear();
+
+        for &(local1, local2, normal, f1, f2) in &warm_start.contacts {
+            let world2 = mab * local2;
+            let contact = Contact::new_wo_depth(local1, world2, normal);
+
+            if -contact.depth <= prediction.linear + margin {
+                self.new_contacts.push((contact, f1, f2));
+            }
+        }
+
+        if self.new_contacts.is_empty() {
+            // Every cached contact separated beyond the prediction margin. This might be a
+            // genuine loss of contact, but it might also just be this per-contact filter
+            // catching up with bodies that have been slowly drifting back together while each
+            // individual step stayed under the warm-start pose thresholds. Only a full SAT/GJK
+            // recompute can tell the difference, so fall through to one instead of latching
+            // onto an empty manifold until the pose drifts past the thresholds.
+            return false;
+        }
+
+        true
+    }
+
+    /// Pushes every point of `self.new_contacts` outward along its contact normal by the
+    /// shapes' respective border radii and recomputes the depth from the inflated points
+    /// (equivalent to adding the combined radius to the core hulls' penetration depth, i.e.
+    /// subtracting it from their separation).
+    ///
+    /// The feature ids are left untouched: they still identify the *core* polyhedron feature,
+    /// which is what warm-starting and the constraint solver's feature tracking key off.
+    fn inflate_contacts(&mut self, border_radius1: N, border_radius2: N) {
+        if border_radius1 <= na::zero() && border_radius2 <= na::zero() {
+            return;
+        }
+
+        for &mut (ref mut c, _, _) in &mut self.new_contacts {
+            let world1 = c.world1 + *c.normal * border_radius1;
+            let world2 = c.world2 - *c.normal * border_radius2;
+            *c = Contact::new_wo_depth(world1, world2, c.normal);
+        }
+    }
+
+    /// Sets the faces neighboring shape 1's primary contact feature. They apply to every later
+    /// `update` until replaced by another call.
+    ///
+    /// Composite generators that iterate a chain of segments or triangles (polylines,
+    /// triangle meshes, heightfields) call this before each `update` with the up-to-two
+    /// (segment) or up-to-three (triangle) faces sharing an edge with the current sub-shape,
+    /// so the contact normal can be constrained against catching on the shared "ghost"
+    /// vertex/edge. Leave empty (the default) for ordinary, non-chained polyhedra.
+    pub fn set_adjacent_edges1(&mut self, adjacent_edges: Vec<AdjacentEdge<N>>) {
+        self.adjacent_edges1 = adjacent_edges;
+    }
+
+    /// Constrains `normal` (found with separation `sep`, expressed in shape 1's local space)
+    /// against the faces adjacent to shape 1's primary contact feature.
+    ///
+    /// Returns the (possibly clamped) normal to use, or `None` if the true minimum separation
+    /// belongs to one of the adjacent edges rather than the primary one, meaning this contact
+    /// should be dropped entirely in favor of the adjacent edge's own manifold.
+    fn constrain_to_adjacent_edges<G1: ?Sized, G2: ?Sized>(
+        &self,
+        cpa: &G1,
+        cpb: &G2,
+        mab: &Isometry<N>,
+        primary_normal: Unit<Vector<N>>,
+        sep: N,
+    ) -> Option<Unit<Vector<N>>>
+    where
+        G1: ConvexPolyhedron<N>,
+        G2: ConvexPolyhedron<N>,
+    {
+        let mut normal = primary_normal;
+        let mut min_sep = sep;
+        let mut min_is_primary = true;
+
+        for adj in &self.adjacent_edges1 {
+            if adj.classify(&primary_normal) != AdjacentEdgeClass::Convex {
+                // Flat edges never constrain the normal; concave ones own their own manifold.
+                continue;
+            }
+
+            let point = CSOPoint::from_shapes_toward_local1(cpa, mab, cpb, &adj.normal);
+            let adj_sep = -point.point.coords.dot(&*adj.normal);
+
+            if adj_sep < min_sep {
+                min_sep = adj_sep;
+                min_is_primary = false;
+            }
+
+            normal = adj.clamp(&primary_normal, normal);
+        }
+
+        if min_is_primary {
+            Some(normal)
+        } else {
+            None
         }
     }
 
+    /// `is_recompute` distinguishes a genuine SAT/GJK recompute from a warm-start reuse: only
+    /// a recompute moves `warm_start.mab` forward, so `try_update_contacts`'s pose-delta
+    /// thresholds bound drift accumulated since the last real recompute, not merely since the
+    /// last call (see the chunk0-3 review fix).
     fn save_new_contacts_as_contact_manifold<G1: ?Sized, G2: ?Sized>(
         &mut self,
         m12: &Isometry<N>,
@@ -78,12 +626,15 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
         m2: &Isometry<N>,
         g2: &G2,
         ids: &mut IdAllocator,
+        is_recompute: bool,
     ) where
         G1: ConvexPolyhedron<N>,
         G2: ConvexPolyhedron<N>,
     {
         self.contact_manifold.save_cache_and_clear(ids);
 
+        let mut warm_start_contacts = Vec::with_capacity(self.new_contacts.len());
+
         for (mut c, f1, f2) in self.new_contacts.drain(..) {
             let mut kinematic = ContactKinematic::new();
             let local1 = c.world1;
@@ -91,6 +642,8 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
             let n1 = g1.normal_cone(f1);
             let n2 = g2.normal_cone(f2);
 
+            warm_start_contacts.push((local1, local2, c.normal, f1, f2));
+
             match f1 {
                 FeatureId::Face(..) => kinematic.set_plane1(f1, local1, n1.unwrap_half_line()),
                 #[cfg(feature = "dim3")]
@@ -125,9 +678,149 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
             c.transform(m1);
             let _ = self.contact_manifold.push(c, kinematic, ids);
         }
+
+        let recompute_mab = if is_recompute {
+            *m12
+        } else {
+            // A warm-start reuse: keep anchoring the drift thresholds to the last genuine
+            // recompute's pose instead of sliding them forward every call.
+            self.warm_start.as_ref().map_or(*m12, |ws| ws.mab)
+        };
+
+        self.warm_start = Some(WarmStartCache {
+            mab: recompute_mab,
+            contacts: warm_start_contacts,
+        });
+    }
+
+    /// Searches for the SAT axis of maximum separation (minimum penetration) between `cpa`
+    /// and `cpb`, restricted to the face normals of `sat_a`/`sat_b` and, in 3D, the cross
+    /// products of their edge directions.
+    ///
+    /// Returns the winning axis, expressed in `cpa`'s local space, the signed separation along
+    /// it (negative when the shapes overlap), and whether the axis came from an edge-edge cross
+    /// product rather than a face normal. Returns `None` if neither shape contributed a usable
+    /// axis (e.g. a degenerate triangle).
+    fn sat_find_axis<G1: ?Sized, G2: ?Sized>(
+        cpa: &G1,
+        sat_a: &SatShape<N>,
+        cpb: &G2,
+        sat_b: &SatShape<N>,
+        mab: &Isometry<N>,
+    ) -> Option<(Unit<Vector<N>>, N, bool)>
+    where
+        G1: ConvexPolyhedron<N>,
+        G2: ConvexPolyhedron<N>,
+    {
+        let mut best: Option<(Unit<Vector<N>>, N, bool)> = None;
+
+        let mut consider =
+            |axis: Unit<Vector<N>>, is_edge_edge: bool, best: &mut Option<(Unit<Vector<N>>, N, bool)>| {
+                let point = CSOPoint::from_shapes_toward_local1(cpa, mab, cpb, &axis);
+                let sep = -point.point.coords.dot(&*axis);
+
+                if best.map(|(_, best_sep, _)| sep > best_sep).unwrap_or(true) {
+                    *best = Some((axis, sep, is_edge_edge));
+                }
+            };
+
+        for (axis, _) in sat_a.local_face_axes() {
+            consider(axis, false, &mut best);
+        }
+
+        for (axis_b, _) in sat_b.local_face_axes() {
+            let axis = Unit::new_unchecked(mab.rotation * *axis_b);
+            consider(axis, false, &mut best);
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            for dir_a in sat_a.local_edge_dirs() {
+                for dir_b in sat_b.local_edge_dirs() {
+                    let dir_b_1 = mab.rotation * *dir_b;
+
+                    if let Some(axis) = Unit::try_new(dir_a.cross(&dir_b_1), N::default_epsilon())
+                    {
+                        consider(axis, true, &mut best);
+                        consider(Unit::new_unchecked(-*axis), true, &mut best);
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Closest points between the two witness edges of an edge-edge SAT axis, assuming
+    /// `manifold1`/`manifold2` were just filled by `local_support_feature_toward`/
+    /// `support_feature_toward` along that axis (both already expressed in shape 1's local
+    /// space). Falls back to the lone vertex on whichever side the support feature degenerated
+    /// to a point (e.g. perfectly axis-aligned edges), which is already the closest point there.
+    fn closest_points_between_edges(
+        manifold1: &ConvexPolygonalFeature<N>,
+        manifold2: &ConvexPolygonalFeature<N>,
+    ) -> (Point<N>, Point<N>) {
+        let has_edge1 = manifold1.vertices.len() >= 2;
+        let has_edge2 = manifold2.vertices.len() >= 2;
+
+        if has_edge1 && has_edge2 {
+            let seg1 = Segment::new(manifold1.vertices[0], manifold1.vertices[1]);
+            let seg2 = Segment::new(manifold2.vertices[0], manifold2.vertices[1]);
+
+            let (loc1, loc2) = closest_points_internal::segment_against_segment_with_locations_nD(
+                (&manifold1.vertices[0], &manifold1.vertices[1]),
+                (&manifold2.vertices[0], &manifold2.vertices[1]),
+            );
+
+            (seg1.point_at(&loc1), seg2.point_at(&loc2))
+        } else if has_edge1 {
+            // The axis degenerated to a vertex on shape 2: the true closest point on shape 1's
+            // witness edge is this vertex's projection onto it, not an arbitrary endpoint.
+            let p2 = manifold2.vertices[0];
+            let p1 = Self::closest_point_on_segment(
+                &p2,
+                &manifold1.vertices[0],
+                &manifold1.vertices[1],
+            );
+            (p1, p2)
+        } else if has_edge2 {
+            let p1 = manifold1.vertices[0];
+            let p2 = Self::closest_point_on_segment(
+                &p1,
+                &manifold2.vertices[0],
+                &manifold2.vertices[1],
+            );
+            (p1, p2)
+        } else {
+            (manifold1.vertices[0], manifold2.vertices[0])
+        }
+    }
+
+    /// The closest point to `p` on segment `a`-`b`, clamped to the segment's extent.
+    fn closest_point_on_segment(p: &Point<N>, a: &Point<N>, b: &Point<N>) -> Point<N> {
+        let ab = *b - *a;
+        let denom = na::dot(&ab, &ab);
+
+        if denom <= N::default_epsilon() {
+            return *a;
+        }
+
+        let mut t = na::dot(&(*p - *a), &ab) / denom;
+        if t < na::zero() {
+            t = na::zero();
+        } else if t > na::one() {
+            t = na::one();
+        }
+
+        *a + ab * t
     }
 
-    fn clip_polyfaces(&mut self, prediction: &ContactPrediction<N>, normal: Unit<Vector<N>>) {
+    fn clip_polyfaces(
+        &mut self,
+        prediction: &ContactPrediction<N>,
+        normal: Unit<Vector<N>>,
+        margin: N,
+    ) {
         self.clip_cache.clear();
 
         #[cfg(feature = "dim2")]
@@ -181,7 +874,7 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
                 let p2 = *seg2.a();
                 let contact = Contact::new_wo_depth(p1, p2, normal);
 
-                if -contact.depth <= prediction.linear {
+                if -contact.depth <= prediction.linear + margin {
                     self.new_contacts
                         .push((contact, self.manifold1.feature_id, features2[0]));
                 }
@@ -191,7 +884,7 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
                 let p2 = seg2.point_at(&SegmentPointLocation::OnEdge([_1 - bcoord, bcoord]));
                 let contact = Contact::new_wo_depth(p1, p2, normal);
 
-                if -contact.depth <= prediction.linear {
+                if -contact.depth <= prediction.linear + margin {
                     self.new_contacts
                         .push((contact, features1[0], self.manifold2.feature_id));
                 }
@@ -203,7 +896,7 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
                 let p2 = *seg2.b();
                 let contact = Contact::new_wo_depth(p1, p2, normal);
 
-                if -contact.depth <= prediction.linear {
+                if -contact.depth <= prediction.linear + margin {
                     self.new_contacts
                         .push((contact, self.manifold1.feature_id, features2[1]));
                 }
@@ -213,7 +906,7 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
                 let p2 = seg2.point_at(&SegmentPointLocation::OnEdge([_1 - bcoord, bcoord]));
                 let contact = Contact::new_wo_depth(p1, p2, normal);
 
-                if -contact.depth <= prediction.linear {
+                if -contact.depth <= prediction.linear + margin {
                     self.new_contacts
                         .push((contact, features1[1], self.manifold2.feature_id));
                 }
@@ -254,204 +947,75 @@ impl<N: Real> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
                 let coords = Point2::new(na::dot(&basis[0], &dpt), na::dot(&basis[1], &dpt));
                 self.clip_cache.poly2.push(coords);
             }
-            /*
-            {
-                let clip_cache = &self.clip_cache;
-                let manifold1 = &self.manifold1;
-                let manifold2 = &self.manifold2;
-                let new_contacts = &mut self.new_contacts;
-
-                utils::convex_polygons_intersection(
-                    &clip_cache.poly1,
-                    &clip_cache.poly2,
-                    |loc1, loc2| match (loc1, loc2) {
-                        (Some(ref loc1), Some(ref loc2)) => {
-                            let (world1, f1) = match loc1 {
-                                PolylinePointLocation::OnVertex(i) => {
-                                    (manifold1.vertices[*i], manifold1.vertices_id[*i])
-                                }
-                                PolylinePointLocation::OnEdge(i1, i2, bcoords) => {
-                                    let world1 = manifold1.vertices[*i1] * bcoords[0]
-                                        + manifold1.vertices[*i2].coords * bcoords[1];
-
-                                    if manifold1.edges_id.len() == 1 {
-                                        (world1, manifold1.feature_id)
-                                    } else {
-                                        (world1, manifold1.edges_id[*i1])
-                                    }
-                                }
-                            };
-
-                            let (world2, f2) = match loc2 {
-                                PolylinePointLocation::OnVertex(i) => {
-                                    (manifold2.vertices[*i], manifold2.vertices_id[*i])
-                                }
-                                PolylinePointLocation::OnEdge(i1, i2, bcoords) => {
-                                    let world2 = manifold2.vertices[*i1] * bcoords[0]
-                                        + manifold2.vertices[*i2].coords * bcoords[1];
-                                    if manifold2.edges_id.len() == 1 {
-                                        (world2, manifold2.feature_id)
-                                    } else {
-                                        (world2, manifold2.edges_id[*i1])
-                                    }
-                                }
-                            };
-
-                            let contact = Contact::new_wo_depth(world1, world2, normal);
-
-                            if -contact.depth <= prediction.linear {
-                                new_contacts.push((contact, f1, f2));
-                            }
-                        }
-                        (None, Some(PolylinePointLocation::OnVertex(i))) => {
-                            if manifold1.normal.is_none() {
-                                // FIXME: special case not handled yet.
-                                // Here, et seems we have an edge vertex exactly on
-                                // an edge feature interior (which for some reasons has
-                                // not been detected as a point-on-edge case by the intersection algorithm).
-                                return;
-                            }
-
-                            let pt = &clip_cache.poly2[i];
-                            let origin = ref_pt + basis[0] * pt.x + basis[1] * pt.y;
-                            let n1 = manifold1.normal.as_ref().unwrap().unwrap();
-                            let p1 = &manifold1.vertices[0];
-
-                            if let Some(toi1) = ray_internal::plane_toi_with_line(
-                                p1,
-                                &n1,
-                                &origin,
-                                &normal.unwrap(),
-                            ) {
-                                let world1 = origin + normal.unwrap() * toi1;
-                                let world2 = manifold2.vertices[i];
-                                let f1 = manifold1.feature_id;
-                                let f2 = manifold2.vertices_id[i];
-                                let contact = Contact::new_wo_depth(world1, world2, normal);
-
-                                if -contact.depth <= prediction.linear {
-                                    new_contacts.push((contact, f1, f2));
-                                }
-                            }
-                        }
-                        (Some(PolylinePointLocation::OnVertex(i)), None) => {
-                            if manifold2.normal.is_none() {
-                                // FIXME: special case not handled yet.
-                                // Here, et seems we have an edge vertex exactly on
-                                // an edge feature interior (which for some reasons has
-                                // not been detected as a point-on-edge case by the intersection algorithm).
-                                return;
-                            }
+            self.new_contacts.clear();
 
-                            let pt = &clip_cache.poly1[i];
-                            let origin = ref_pt + basis[0] * pt.x + basis[1] * pt.y;
-
-                            let n2 = manifold2.normal.as_ref().unwrap().unwrap();
-                            let p2 = &manifold2.vertices[0];
-                            if let Some(toi2) = ray_internal::plane_toi_with_line(
-                                p2,
-                                &n2,
-                                &origin,
-                                &normal.unwrap(),
-                            ) {
-                                let world2 = origin + normal.unwrap() * toi2;
-                                let world1 = manifold1.vertices[i];
-                                let f2 = manifold2.feature_id;
-                                let f1 = manifold1.vertices_id[i];
-                                let contact = Contact::new_wo_depth(world1, world2, normal);
-
-                                if -contact.depth <= prediction.linear {
-                                    new_contacts.push((contact, f1, f2));
-                                }
-                            }
-                        }
-                        _ => {}
-                    },
-                );
-            }*/
+            // Clip `poly1` against `poly2` with a convexity-specialized Sutherland-Hodgman
+            // pass, tracking where each output vertex came from so we can recover the
+            // correct `FeatureId` pair and re-raycast it onto the opposite face plane.
+            let clipped = clip_convex_polygons(&self.clip_cache.poly1, &self.clip_cache.poly2);
 
-            self.new_contacts.clear();
-            if self.clip_cache.poly2.len() > 2 {
-                for i in 0..self.clip_cache.poly1.len() {
-                    let pt = &self.clip_cache.poly1[i];
+            for (pt, source) in &clipped {
+                let origin = ref_pt + basis[0] * pt.x + basis[1] * pt.y;
 
-                    if utils::point_in_poly2d(pt, &self.clip_cache.poly2) {
-                        let origin = ref_pt + basis[0] * pt.x + basis[1] * pt.y;
+                let (world1, f1) = match *source {
+                    ClipVertexSource::Poly1Vertex(i) => {
+                        (self.manifold1.vertices[i], self.manifold1.vertices_id[i])
+                    }
+                    _ => {
+                        let n1 = match self.manifold1.normal.as_ref() {
+                            Some(n1) => n1.unwrap(),
+                            None => continue,
+                        };
+                        let p1 = &self.manifold1.vertices[0];
 
-                        let n2 = self.manifold2.normal.as_ref().unwrap().unwrap();
-                        let p2 = &self.manifold2.vertices[0];
-                        if let Some(toi2) =
-                            ray_internal::plane_toi_with_line(p2, &n2, &origin, &normal.unwrap())
+                        match ray_internal::plane_toi_with_line(p1, &n1, &origin, &normal.unwrap())
                         {
-                            let world2 = origin + normal.unwrap() * toi2;
-                            let world1 = self.manifold1.vertices[i];
-                            let f2 = self.manifold2.feature_id;
-                            let f1 = self.manifold1.vertices_id[i];
-                            let contact = Contact::new_wo_depth(world1, world2, normal);
-
-                            if -contact.depth <= prediction.linear {
-                                self.new_contacts.push((contact, f1, f2));
-                            }
+                            Some(toi1) => (origin + normal.unwrap() * toi1, self.manifold1.feature_id),
+                            None => continue,
                         }
                     }
-                }
-            }
+                };
 
-            if self.clip_cache.poly1.len() > 2 {
-                for i in 0..self.clip_cache.poly2.len() {
-                    let pt = &self.clip_cache.poly2[i];
-
-                    if utils::point_in_poly2d(pt, &self.clip_cache.poly1) {
-                        let origin = ref_pt + basis[0] * pt.x + basis[1] * pt.y;
+                let (world2, f2) = match *source {
+                    ClipVertexSource::Poly2Vertex(i) => {
+                        (self.manifold2.vertices[i], self.manifold2.vertices_id[i])
+                    }
+                    _ => {
+                        let n2 = match self.manifold2.normal.as_ref() {
+                            Some(n2) => n2.unwrap(),
+                            None => continue,
+                        };
+                        let p2 = &self.manifold2.vertices[0];
 
-                        let n1 = self.manifold1.normal.as_ref().unwrap().unwrap();
-                        let p1 = &self.manifold1.vertices[0];
-                        if let Some(toi1) =
-                            ray_internal::plane_toi_with_line(p1, &n1, &origin, &normal.unwrap())
+                        match ray_internal::plane_toi_with_line(p2, &n2, &origin, &normal.unwrap())
                         {
-                            let world1 = origin + normal.unwrap() * toi1;
-                            let world2 = self.manifold2.vertices[i];
-                            let f1 = self.manifold1.feature_id;
-                            let f2 = self.manifold2.vertices_id[i];
-                            let contact = Contact::new_wo_depth(world1, world2, normal);
-
-                            if -contact.depth <= prediction.linear {
-                                self.new_contacts.push((contact, f1, f2));
-                            }
+                            Some(toi2) => (origin + normal.unwrap() * toi2, self.manifold2.feature_id),
+                            None => continue,
                         }
                     }
-                }
-            }
-
-            let nedges1 = self.manifold1.nedges();
-            let nedges2 = self.manifold2.nedges();
-
-            for i1 in 0..nedges1 {
-                let j1 = (i1 + 1) % self.clip_cache.poly1.len();
-                let seg1 = (&self.clip_cache.poly1[i1], &self.clip_cache.poly1[j1]);
+                };
+
+                let (f1, f2) = match *source {
+                    ClipVertexSource::Poly1Vertex(_) | ClipVertexSource::Poly2Vertex(_) => (f1, f2),
+                    ClipVertexSource::EdgeEdge(i1, i2) => {
+                        let e1 = if self.manifold1.edges_id.len() == 1 {
+                            self.manifold1.feature_id
+                        } else {
+                            self.manifold1.edges_id[i1]
+                        };
+                        let e2 = if self.manifold2.edges_id.len() == 1 {
+                            self.manifold2.feature_id
+                        } else {
+                            self.manifold2.edges_id[i2]
+                        };
+                        (e1, e2)
+                    }
+                };
 
-                for i2 in 0..nedges2 {
-                    let j2 = (i2 + 1) % self.clip_cache.poly2.len();
-                    let seg2 = (&self.clip_cache.poly2[i2], &self.clip_cache.poly2[j2]);
+                let contact = Contact::new_wo_depth(world1, world2, normal);
 
-                    if let (SegmentPointLocation::OnEdge(e1), SegmentPointLocation::OnEdge(e2)) =
-                        closest_points_internal::segment_against_segment_with_locations_nD(
-                            seg1, seg2,
-                        ) {
-                        let original1 =
-                            Segment::new(self.manifold1.vertices[i1], self.manifold1.vertices[j1]);
-                        let original2 =
-                            Segment::new(self.manifold2.vertices[i2], self.manifold2.vertices[j2]);
-                        let world1 = original1.point_at(&SegmentPointLocation::OnEdge(e1));
-                        let world2 = original2.point_at(&SegmentPointLocation::OnEdge(e2));
-                        let f1 = self.manifold1.edges_id[i1];
-                        let f2 = self.manifold2.edges_id[i2];
-                        let contact = Contact::new_wo_depth(world1, world2, normal);
-
-                        if -contact.depth <= prediction.linear {
-                            self.new_contacts.push((contact, f1, f2));
-                        }
-                    }
+                if -contact.depth <= prediction.linear + margin {
+                    self.new_contacts.push((contact, f1, f2));
                 }
             }
         }
@@ -475,9 +1039,13 @@ impl<N: Real> ContactManifoldGenerator<N> for ConvexPolyhedronConvexPolyhedronMa
         if let (Some(cpa), Some(cpb)) = (a.as_convex_polyhedron(), b.as_convex_polyhedron()) {
             let mab = ma.inverse() * mb;
 
+            let border_radius1 = polygonal_feature_map_border_radius(a);
+            let border_radius2 = polygonal_feature_map_border_radius(b);
+            let margin = border_radius1 + border_radius2;
+
             if let Some(sep_axis) = self.sep_axis {
                 let point = CSOPoint::from_shapes_toward_local1(cpa, &mab, cpb, &sep_axis);
-                if -point.point.coords.dot(&*sep_axis) > prediction.linear {
+                if -point.point.coords.dot(&*sep_axis) > prediction.linear + margin {
                     self.contact_manifold.save_cache_and_clear(ids);
                     return true;
                 }
@@ -488,60 +1056,149 @@ impl<N: Real> ContactManifoldGenerator<N> for ConvexPolyhedronConvexPolyhedronMa
             self.contact_manifold.set_subshape_id1(ida);
             self.contact_manifold.set_subshape_id2(idb);
 
-            self.simplex.transform2(&mab);
-
-            let contact = contacts_internal::support_map_against_support_map_with_simplex(
-                cpa,
-                &mab,
-                cpb,
-                prediction.linear,
-                &mut self.simplex,
-            );
-
-            self.simplex.transform2(&mab.inverse());
+            // Warm-start: the shapes barely moved relative to each other since the manifold
+            // was last fully computed, so just re-project its contacts instead of running GJK
+            // and the face clipper again.
+            if self.try_update_contacts(&mab, prediction, margin) {
+                self.save_new_contacts_as_contact_manifold(&mab, ma, cpa, mb, cpb, ids, false);
+                return true;
+            }
 
-            // Generate a contact manifold.
             self.new_contacts.clear();
             self.manifold1.clear();
             self.manifold2.clear();
 
-            match contact {
-                GJKResult::ClosestPoints(local1, local2_1, local_normal1) => {
-                    let contact = Contact::new_wo_depth(local1, local2_1, local_normal1);
+            // Fast path: both shapes are boxes/triangles, so their faces can be enumerated
+            // directly and the separating axis found without any simplex iteration.
+            if let (Some(sat_a), Some(sat_b)) = (SatShape::from_shape(a), SatShape::from_shape(b))
+            {
+                if let Some((axis, sep, is_edge_edge)) =
+                    Self::sat_find_axis(cpa, &sat_a, cpb, &sat_b, &mab)
+                {
+                    if sep > prediction.linear + margin {
+                        self.sep_axis = Some(axis);
+                    } else if let Some(axis) =
+                        self.constrain_to_adjacent_edges(cpa, cpb, &mab, axis, sep)
+                    {
+                        if is_edge_edge {
+                            // Face-clipping a cross-product axis would pick an arbitrary
+                            // vertex of whichever face `clip_polyfaces` falls back to, not the
+                            // true closest points between the two separating edges. Ask each
+                            // shape for its exact support feature along this axis instead
+                            // (the witness edge that produced it, or a lone vertex where it
+                            // degenerates) and take the closest points between those features.
+                            cpa.local_support_feature_toward(
+                                &axis,
+                                prediction.angular1,
+                                &mut self.manifold1,
+                            );
+                            cpb.support_feature_toward(
+                                &mab,
+                                &-axis,
+                                prediction.angular2,
+                                &mut self.manifold2,
+                            );
+
+                            let (world1, world2) =
+                                Self::closest_points_between_edges(&self.manifold1, &self.manifold2);
+                            let contact = Contact::new_wo_depth(world1, world2, axis);
+                            self.new_contacts.push((
+                                contact,
+                                self.manifold1.feature_id,
+                                self.manifold2.feature_id,
+                            ));
+                        } else {
+                            cpa.local_support_face_toward(&axis, &mut self.manifold1);
+                            cpb.support_face_toward(&mab, &-axis, &mut self.manifold2);
+                            self.clip_polyfaces(prediction, axis, margin);
+
+                            if self.new_contacts.len() == 0 {
+                                let contact = Contact::new_wo_depth(
+                                    self.manifold1.vertices[0],
+                                    self.manifold2.vertices[0],
+                                    axis,
+                                );
+                                self.new_contacts.push((
+                                    contact,
+                                    self.manifold1.feature_id,
+                                    self.manifold2.feature_id,
+                                ));
+                            }
+                        }
+                    }
+                }
+            } else {
+                self.simplex.transform2(&mab);
+
+                let contact = contacts_internal::support_map_against_support_map_with_simplex(
+                    cpa,
+                    &mab,
+                    cpb,
+                    prediction.linear + margin,
+                    &mut self.simplex,
+                );
 
-                    if contact.depth > na::zero() {
-                        cpa.local_support_face_toward(&contact.normal, &mut self.manifold1);
-                        cpb.support_face_toward(&mab, &-contact.normal, &mut self.manifold2);
-                        self.clip_polyfaces(prediction, contact.normal);
-                    } else {
-                        cpa.local_support_feature_toward(
-                            &contact.normal,
-                            prediction.angular1,
-                            &mut self.manifold1,
-                        );
-                        cpb.support_feature_toward(
-                            &mab,
-                            &-contact.normal,
-                            prediction.angular2,
-                            &mut self.manifold2,
-                        );
+                self.simplex.transform2(&mab.inverse());
 
-                        self.clip_polyfaces(prediction, contact.normal);
-                    }
+                match contact {
+                    GJKResult::ClosestPoints(local1, local2_1, local_normal1) => {
+                        let contact = Contact::new_wo_depth(local1, local2_1, local_normal1);
+
+                        // The true minimum separation might belong to a neighboring chain edge
+                        // instead of this one: constrain (or outright drop) the normal in both
+                        // the deep-penetration and the near-surface/speculative case, since a
+                        // body gliding along an edge chain spends most of its time in the
+                        // latter (see the chunk0-2 review fix).
+                        if let Some(normal) = self.constrain_to_adjacent_edges(
+                            cpa,
+                            cpb,
+                            &mab,
+                            contact.normal,
+                            -contact.depth,
+                        ) {
+                            if contact.depth > na::zero() {
+                                cpa.local_support_face_toward(&normal, &mut self.manifold1);
+                                cpb.support_face_toward(&mab, &-normal, &mut self.manifold2);
+                                self.clip_polyfaces(prediction, normal, margin);
+                            } else {
+                                cpa.local_support_feature_toward(
+                                    &normal,
+                                    prediction.angular1,
+                                    &mut self.manifold1,
+                                );
+                                cpb.support_feature_toward(
+                                    &mab,
+                                    &-normal,
+                                    prediction.angular2,
+                                    &mut self.manifold2,
+                                );
+
+                                self.clip_polyfaces(prediction, normal, margin);
+                            }
 
-                    if self.new_contacts.len() == 0 {
-                        self.new_contacts.push((
-                            contact,
-                            self.manifold1.feature_id,
-                            self.manifold2.feature_id,
-                        ));
+                            if self.new_contacts.len() == 0 {
+                                let contact =
+                                    Contact::new_wo_depth(contact.world1, contact.world2, normal);
+                                self.new_contacts.push((
+                                    contact,
+                                    self.manifold1.feature_id,
+                                    self.manifold2.feature_id,
+                                ));
+                            }
+                        }
+                        // Else: the true minimum separation belongs to an adjacent edge, which
+                        // generates its own manifold for this pair; dropping this contact
+                        // entirely (rather than resurrecting it with stale, cleared manifold
+                        // feature ids) avoids the `FeatureId::Unknown` panic in
+                        // `save_new_contacts_as_contact_manifold`.
                     }
+                    GJKResult::NoIntersection(sep_axis) => self.sep_axis = Some(sep_axis),
+                    _ => {}
                 }
-                GJKResult::NoIntersection(sep_axis) => self.sep_axis = Some(sep_axis),
-                _ => {}
             }
 
-            self.save_new_contacts_as_contact_manifold(&mab, ma, cpa, mb, cpb, ids);
+            self.inflate_contacts(border_radius1, border_radius2);
+            self.save_new_contacts_as_contact_manifold(&mab, ma, cpa, mb, cpb, ids, true);
 
             true
         } else {
@@ -561,3 +1218,173 @@ impl<N: Real> ContactManifoldGenerator<N> for ConvexPolyhedronConvexPolyhedronMa
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn axis2d(i: usize) -> Unit<Vector<f64>> {
+        let mut v: Vector<f64> = na::zero();
+        v[i] = 1.0;
+        Unit::new_unchecked(v)
+    }
+
+    fn any_edge_dir() -> Unit<Vector<f64>> {
+        let mut v: Vector<f64> = na::zero();
+        v[Vector::<f64>::dimension() - 1] = 1.0;
+        Unit::new_unchecked(v)
+    }
+
+    #[test]
+    fn adjacent_edge_classifies_a_square_corner_as_convex() {
+        // Two faces meeting at a right angle, normals pointing away from each other (like two
+        // faces of a box): this is the textbook convex fold.
+        let primary_normal = axis2d(0);
+        let adjacent = AdjacentEdge::new(axis2d(1), any_edge_dir());
+
+        assert_eq!(
+            adjacent.classify(&primary_normal),
+            AdjacentEdgeClass::Convex
+        );
+    }
+
+    #[test]
+    fn adjacent_edge_classifies_a_reflex_corner_as_concave() {
+        let primary_normal = axis2d(0);
+        let mut n: Vector<f64> = na::zero();
+        n[1] = -1.0;
+        let adjacent = AdjacentEdge::new(Unit::new_unchecked(n), any_edge_dir());
+
+        assert_eq!(
+            adjacent.classify(&primary_normal),
+            AdjacentEdgeClass::Concave
+        );
+    }
+
+    #[test]
+    fn adjacent_edge_classifies_a_coplanar_neighbor_as_flat() {
+        let primary_normal = axis2d(0);
+        let adjacent = AdjacentEdge::new(axis2d(0), any_edge_dir());
+
+        assert_eq!(adjacent.classify(&primary_normal), AdjacentEdgeClass::Flat);
+    }
+
+    #[test]
+    fn clip_convex_polygons_finds_the_overlap_of_two_offset_squares() {
+        let poly1 = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let poly2 = [
+            Point2::new(0.5, 0.0),
+            Point2::new(1.5, 0.0),
+            Point2::new(1.5, 1.0),
+            Point2::new(0.5, 1.0),
+        ];
+
+        let clipped = clip_convex_polygons(&poly1, &poly2);
+        let pts: Vec<_> = clipped.iter().map(|(p, _)| *p).collect();
+        let area = polygon_signed_area2d(&pts).abs() / na::convert(2.0);
+
+        assert!((area - 0.5).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn clip_convex_polygons_finds_the_overlap_when_poly2_is_clockwise() {
+        // The production case: two opposing support faces seen in the same 2D basis, so one of
+        // them is wound clockwise (here `poly2`, giving `orient == -1` inside the function).
+        let poly1 = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let poly2 = [
+            Point2::new(0.5, 0.0),
+            Point2::new(0.5, 1.0),
+            Point2::new(1.5, 1.0),
+            Point2::new(1.5, 0.0),
+        ];
+
+        assert!(polygon_signed_area2d(&poly2) < 0.0);
+
+        let clipped = clip_convex_polygons(&poly1, &poly2);
+        let pts: Vec<_> = clipped.iter().map(|(p, _)| *p).collect();
+        let area = polygon_signed_area2d(&pts).abs() / na::convert(2.0);
+
+        assert!((area - 0.5).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn clip_convex_polygons_is_empty_for_disjoint_squares() {
+        let poly1 = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        let poly2 = [
+            Point2::new(5.0, 0.0),
+            Point2::new(6.0, 0.0),
+            Point2::new(6.0, 1.0),
+            Point2::new(5.0, 1.0),
+        ];
+
+        assert!(clip_convex_polygons(&poly1, &poly2).is_empty());
+    }
+
+    #[test]
+    fn inflate_contacts_pushes_each_point_outward_along_the_normal() {
+        let mut gen = ConvexPolyhedronConvexPolyhedronManifoldGenerator::<f64>::new();
+        let normal = axis2d(0);
+        let p: Point<f64> = Point::origin();
+        let contact = Contact::new_wo_depth(p, p, normal);
+        gen.new_contacts
+            .push((contact, FeatureId::Unknown, FeatureId::Unknown));
+
+        gen.inflate_contacts(0.2, 0.3);
+
+        let (inflated, _, _) = gen.new_contacts[0];
+        assert!((inflated.world1 - (p + *normal * 0.2)).norm() < 1.0e-9);
+        assert!((inflated.world2 - (p - *normal * 0.3)).norm() < 1.0e-9);
+    }
+
+    #[test]
+    fn try_update_contacts_keeps_a_cached_contact_within_the_margin() {
+        let mut gen = ConvexPolyhedronConvexPolyhedronManifoldGenerator::<f64>::new();
+        let normal = axis2d(0);
+        let p: Point<f64> = Point::origin();
+
+        gen.warm_start = Some(WarmStartCache {
+            mab: Isometry::identity(),
+            contacts: vec![(p, p, normal, FeatureId::Unknown, FeatureId::Unknown)],
+        });
+
+        let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+        assert!(gen.try_update_contacts(&Isometry::identity(), &prediction, 0.0));
+        assert_eq!(gen.new_contacts.len(), 1);
+    }
+
+    #[test]
+    fn try_update_contacts_falls_back_to_a_full_recompute_once_every_contact_is_filtered_out() {
+        // Regression test: if the per-contact separation filter empties `new_contacts`, this
+        // must report failure (not success with an empty manifold), or a slowly-closing gap
+        // could get stuck never regenerating a contact (see the chunk0-3 review fix).
+        let mut gen = ConvexPolyhedronConvexPolyhedronManifoldGenerator::<f64>::new();
+        let normal = axis2d(0);
+        let p: Point<f64> = Point::origin();
+
+        gen.warm_start = Some(WarmStartCache {
+            mab: Isometry::identity(),
+            contacts: vec![(p, p, normal, FeatureId::Unknown, FeatureId::Unknown)],
+        });
+
+        // A contact exactly at zero separation still fails a margin that is itself negative,
+        // regardless of the sign convention `Contact::depth` happens to use.
+        let prediction = ContactPrediction::new(-1.0, 0.0, 0.0);
+        assert!(!gen.try_update_contacts(&Isometry::identity(), &prediction, 0.0));
+        assert!(gen.new_contacts.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+use na::Real;
+
+use math::Isometry;
+use pipeline::narrow_phase::contact_generator::convex_polyhedron_convex_polyhedron_manifold_generator::{
+    AdjacentEdge, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
+};
+use pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use query::{ContactManifold, ContactPrediction};
+use shape::{HeightField, Shape};
+use utils::IdAllocator;
+
+/// Contact manifold computation between a `HeightField` (shape 1) and any other
+/// `ConvexPolyhedron`.
+///
+/// Maintains one `ConvexPolyhedronConvexPolyhedronManifoldGenerator` per triangle of the
+/// height field, feeding each one the up-to-three triangles adjacent to it before every
+/// `update` so contacts don't snag on the "ghost" edges shared between neighboring triangles.
+/// See `ConvexPolyhedronConvexPolyhedronManifoldGenerator::set_adjacent_edges1`.
+#[derive(Clone)]
+pub struct HeightFieldConvexPolyhedronManifoldGenerator<N: Real> {
+    sub_detectors: Vec<ConvexPolyhedronConvexPolyhedronManifoldGenerator<N>>,
+}
+
+impl<N: Real> HeightFieldConvexPolyhedronManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a height field and a convex
+    /// polyhedron.
+    pub fn new() -> Self {
+        HeightFieldConvexPolyhedronManifoldGenerator {
+            sub_detectors: Vec::new(),
+        }
+    }
+
+    fn adjacent_edges(heightfield: &HeightField<N>, i: usize) -> Vec<AdjacentEdge<N>> {
+        let mut adj = Vec::with_capacity(3);
+
+        for k in 0..3 {
+            if let Some(j) = heightfield.triangle_neighbor(i, k) {
+                adj.push(AdjacentEdge::new(
+                    heightfield.triangle_normal(j),
+                    heightfield.triangle_edge_direction(i, k),
+                ));
+            }
+        }
+
+        adj
+    }
+}
+
+impl<N: Real> ContactManifoldGenerator<N> for HeightFieldConvexPolyhedronManifoldGenerator<N> {
+    fn update(
+        &mut self,
+        dispatcher: &ContactDispatcher<N>,
+        ida: usize,
+        ma: &Isometry<N>,
+        a: &Shape<N>,
+        idb: usize,
+        mb: &Isometry<N>,
+        b: &Shape<N>,
+        prediction: &ContactPrediction<N>,
+        ids: &mut IdAllocator,
+    ) -> bool {
+        let heightfield = match a.as_shape::<HeightField<N>>() {
+            Some(h) => h,
+            None => return false,
+        };
+
+        // FIXME: like the polyline dispatcher, this walks every triangle instead of first
+        // narrowing down to the ones whose AABB overlaps `b` through the height field's BVT.
+        if self.sub_detectors.len() != heightfield.num_triangles() {
+            self.sub_detectors = vec![
+                ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+                heightfield.num_triangles()
+            ];
+        }
+
+        let mut any = false;
+
+        for i in 0..heightfield.num_triangles() {
+            let triangle = heightfield.triangle_shape(i);
+            let detector = &mut self.sub_detectors[i];
+            detector.set_adjacent_edges1(Self::adjacent_edges(heightfield, i));
+
+            if detector.update(dispatcher, ida, ma, &triangle, idb, mb, b, prediction, ids) {
+                any = true;
+            }
+        }
+
+        any
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.sub_detectors.iter().map(|d| d.num_contacts()).sum()
+    }
+
+    #[inline]
+    fn contacts<'a: 'b, 'b>(&'a self, out: &'b mut Vec<&'a ContactManifold<N>>) {
+        for d in &self.sub_detectors {
+            d.contacts(out);
+        }
+    }
+}
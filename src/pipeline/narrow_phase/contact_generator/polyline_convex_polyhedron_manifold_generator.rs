@@ -0,0 +1,106 @@
+use na::Real;
+
+use math::Isometry;
+use pipeline::narrow_phase::contact_generator::convex_polyhedron_convex_polyhedron_manifold_generator::{
+    AdjacentEdge, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
+};
+use pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use query::{ContactManifold, ContactPrediction};
+use shape::{Polyline, Shape};
+use utils::IdAllocator;
+
+/// Contact manifold computation between a `Polyline` (shape 1) and any other `ConvexPolyhedron`.
+///
+/// Maintains one `ConvexPolyhedronConvexPolyhedronManifoldGenerator` per edge of the polyline,
+/// feeding each one the up-to-two edges adjacent to it before every `update` so contacts don't
+/// snag on the "ghost" vertex shared between consecutive edges. See
+/// `ConvexPolyhedronConvexPolyhedronManifoldGenerator::set_adjacent_edges1`.
+#[derive(Clone)]
+pub struct PolylineConvexPolyhedronManifoldGenerator<N: Real> {
+    sub_detectors: Vec<ConvexPolyhedronConvexPolyhedronManifoldGenerator<N>>,
+}
+
+impl<N: Real> PolylineConvexPolyhedronManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a polyline and a convex polyhedron.
+    pub fn new() -> Self {
+        PolylineConvexPolyhedronManifoldGenerator {
+            sub_detectors: Vec::new(),
+        }
+    }
+
+    fn adjacent_edges(polyline: &Polyline<N>, i: usize) -> Vec<AdjacentEdge<N>> {
+        let nedges = polyline.num_edges();
+        let mut adj = Vec::with_capacity(2);
+
+        if i > 0 {
+            adj.push(AdjacentEdge::new(
+                polyline.edge_normal(i - 1),
+                polyline.edge_direction(i - 1),
+            ));
+        }
+
+        if i + 1 < nedges {
+            adj.push(AdjacentEdge::new(
+                polyline.edge_normal(i + 1),
+                polyline.edge_direction(i + 1),
+            ));
+        }
+
+        adj
+    }
+}
+
+impl<N: Real> ContactManifoldGenerator<N> for PolylineConvexPolyhedronManifoldGenerator<N> {
+    fn update(
+        &mut self,
+        dispatcher: &ContactDispatcher<N>,
+        ida: usize,
+        ma: &Isometry<N>,
+        a: &Shape<N>,
+        idb: usize,
+        mb: &Isometry<N>,
+        b: &Shape<N>,
+        prediction: &ContactPrediction<N>,
+        ids: &mut IdAllocator,
+    ) -> bool {
+        let polyline = match a.as_shape::<Polyline<N>>() {
+            Some(p) => p,
+            None => return false,
+        };
+
+        // FIXME: this walks every edge instead of first narrowing down to the ones whose AABB
+        // overlaps `b` through the polyline's BVT; a real broad-phase belongs here.
+        if self.sub_detectors.len() != polyline.num_edges() {
+            self.sub_detectors = vec![
+                ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+                polyline.num_edges()
+            ];
+        }
+
+        let mut any = false;
+
+        for i in 0..polyline.num_edges() {
+            let edge = polyline.edge_shape(i);
+            let detector = &mut self.sub_detectors[i];
+            detector.set_adjacent_edges1(Self::adjacent_edges(polyline, i));
+
+            if detector.update(dispatcher, ida, ma, &edge, idb, mb, b, prediction, ids) {
+                any = true;
+            }
+        }
+
+        any
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.sub_detectors.iter().map(|d| d.num_contacts()).sum()
+    }
+
+    #[inline]
+    fn contacts<'a: 'b, 'b>(&'a self, out: &'b mut Vec<&'a ContactManifold<N>>) {
+        for d in &self.sub_detectors {
+            d.contacts(out);
+        }
+    }
+}